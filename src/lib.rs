@@ -4,7 +4,7 @@
 //! # Example usage
 //!
 //! ```no_run
-//! use sns_push_notifications::{Push, Region, SnsClient};
+//! use sns_push_notifications::{Alert, Push, Region, SnsClient};
 //!
 //! # fn main() -> Result<(), Box<std::error::Error>> {
 //! let client = SnsClient::new_checked(Region::EuWest1)?;
@@ -18,8 +18,9 @@
 //!
 //! client.send_push(
 //!     &Push::Alert {
-//!         text: "Hello, World!".to_string(),
+//!         alert: Alert::new("Hello, World!"),
 //!         badge: Some(1),
+//!         options: Default::default(),
 //!     },
 //!     &endpoint_arn,
 //! )?;
@@ -40,16 +41,30 @@
 )]
 #![doc(html_root_url = "https://docs.rs/sns-push-notifications/0.1.1")]
 
+use futures::compat::Future01CompatExt;
+use rusoto_sns::ConfirmSubscriptionInput;
+use rusoto_sns::CreatePlatformApplicationInput;
 use rusoto_sns::CreatePlatformEndpointInput;
+use rusoto_sns::CreateTopicInput;
+use rusoto_sns::MessageAttributeValue;
 use rusoto_sns::PublishInput;
+use rusoto_sns::SetEndpointAttributesInput;
 use rusoto_sns::Sns;
+use rusoto_sns::SubscribeInput;
+use serde::Deserialize;
 use serde::Serialize;
 use serde_json::json;
+use std::collections::HashMap;
 use std::fmt;
 
 pub use rusoto_core::region::Region;
+pub use rusoto_sns::ConfirmSubscriptionError;
+pub use rusoto_sns::CreatePlatformApplicationError;
 pub use rusoto_sns::CreatePlatformEndpointError;
+pub use rusoto_sns::CreateTopicError;
 pub use rusoto_sns::PublishError;
+pub use rusoto_sns::SetEndpointAttributesError;
+pub use rusoto_sns::SubscribeError;
 
 /// A client for interacting with SNS
 pub struct SnsClient {
@@ -99,6 +114,7 @@ impl SnsClient {
             .publish(PublishInput {
                 message: payload,
                 message_structure: Some("json".to_string()),
+                message_attributes: push.to_message_attributes(),
                 target_arn: Some(endpoint_arn.clone()),
                 ..Default::default()
             })
@@ -106,21 +122,346 @@ impl SnsClient {
 
         Ok(())
     }
+
+    /// The async equivalent of [`register_device`](SnsClient::register_device).
+    ///
+    /// SNS's generated client returns a futures 0.1 `RusotoFuture` (the same one
+    /// [`register_device`](SnsClient::register_device) drives with `.sync()`), so this awaits
+    /// it through a `.compat()` shim instead of blocking the current thread, making it safe to
+    /// call from within an async runtime such as Tokio.
+    pub async fn register_device_async(
+        &self,
+        token: &str,
+        platform_application_arn: &str,
+    ) -> Result<EndpointArn, Error> {
+        let res = self
+            .client
+            .create_platform_endpoint(CreatePlatformEndpointInput {
+                platform_application_arn: platform_application_arn.to_string(),
+                token: token.to_string(),
+                ..Default::default()
+            })
+            .compat()
+            .await?;
+
+        Ok(res.endpoint_arn.unwrap())
+    }
+
+    /// The async equivalent of [`send_push`](SnsClient::send_push).
+    ///
+    /// See [`register_device_async`](SnsClient::register_device_async) for why this needs a
+    /// `.compat()` shim.
+    pub async fn send_push_async(
+        &self,
+        push: &Push,
+        endpoint_arn: &EndpointArn,
+    ) -> Result<(), Error> {
+        let payload = push.to_sns_payload();
+
+        self.client
+            .publish(PublishInput {
+                message: payload,
+                message_structure: Some("json".to_string()),
+                message_attributes: push.to_message_attributes(),
+                target_arn: Some(endpoint_arn.clone()),
+                ..Default::default()
+            })
+            .compat()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Send an SMS message directly to a phone number.
+    ///
+    /// Useful for reaching users who don't have a device registered with
+    /// [`register_device`](SnsClient::register_device).
+    pub fn send_sms(&self, sms: &Sms, phone_number: &str) -> Result<(), Error> {
+        self.client
+            .publish(PublishInput {
+                message: sms.message.clone(),
+                phone_number: Some(phone_number.to_string()),
+                message_attributes: Some(sms.to_message_attributes()),
+                ..Default::default()
+            })
+            .sync()?;
+
+        Ok(())
+    }
+
+    /// Create a topic that notifications can be fanned out to.
+    ///
+    /// If a topic with the given name already exists you'll get back its ARN.
+    pub fn create_topic(&self, name: &str) -> Result<TopicArn, Error> {
+        let res = self
+            .client
+            .create_topic(CreateTopicInput {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .sync()?;
+
+        Ok(res.topic_arn.unwrap())
+    }
+
+    /// Subscribe an endpoint to a topic.
+    ///
+    /// `protocol` is one of SNS's subscription protocols, such as `"application"` for a
+    /// platform endpoint, `"email"`, `"sqs"`, or `"https"`. `endpoint` is the corresponding
+    /// address, e.g. an [`EndpointArn`] for `"application"`.
+    pub fn subscribe(
+        &self,
+        topic_arn: &TopicArn,
+        protocol: &str,
+        endpoint: &str,
+    ) -> Result<SubscriptionArn, Error> {
+        let res = self
+            .client
+            .subscribe(SubscribeInput {
+                topic_arn: topic_arn.clone(),
+                protocol: protocol.to_string(),
+                endpoint: Some(endpoint.to_string()),
+                ..Default::default()
+            })
+            .sync()?;
+
+        Ok(res.subscription_arn.unwrap())
+    }
+
+    /// Publish a push notification to every subscriber of a topic, rather than to a single
+    /// endpoint.
+    pub fn publish_to_topic(&self, push: &Push, topic_arn: &TopicArn) -> Result<(), Error> {
+        let payload = push.to_sns_payload();
+
+        self.client
+            .publish(PublishInput {
+                message: payload,
+                message_structure: Some("json".to_string()),
+                message_attributes: push.to_message_attributes(),
+                topic_arn: Some(topic_arn.clone()),
+                ..Default::default()
+            })
+            .sync()?;
+
+        Ok(())
+    }
+
+    /// Create a platform application that devices can be registered against with
+    /// [`register_device`](SnsClient::register_device), instead of creating it manually in the
+    /// SNS dashboard.
+    pub fn create_platform_application(
+        &self,
+        name: &str,
+        platform: Platform,
+        credentials: PlatformCredentials,
+    ) -> Result<PlatformApplicationArn, Error> {
+        let mut attributes = HashMap::new();
+        attributes.insert("PlatformCredential".to_string(), credentials.credential);
+        if let Some(principal) = credentials.principal {
+            attributes.insert("PlatformPrincipal".to_string(), principal);
+        }
+
+        let res = self
+            .client
+            .create_platform_application(CreatePlatformApplicationInput {
+                name: name.to_string(),
+                platform: platform.as_str().to_string(),
+                attributes,
+            })
+            .sync()?;
+
+        Ok(res.platform_application_arn.unwrap())
+    }
+
+    /// Enable or disable an endpoint.
+    ///
+    /// SNS disables an endpoint when it detects that the device's token is no longer valid.
+    /// Re-enabling it is useful once you've obtained a fresh token for the device, e.g. after
+    /// re-registering it with [`register_device`](SnsClient::register_device).
+    pub fn set_endpoint_enabled(
+        &self,
+        endpoint_arn: &EndpointArn,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        let mut attributes = HashMap::new();
+        attributes.insert("Enabled".to_string(), enabled.to_string());
+
+        self.client
+            .set_endpoint_attributes(SetEndpointAttributesInput {
+                endpoint_arn: endpoint_arn.clone(),
+                attributes,
+            })
+            .sync()?;
+
+        Ok(())
+    }
+
+    /// Update the device token an endpoint was registered with.
+    pub fn set_endpoint_token(
+        &self,
+        endpoint_arn: &EndpointArn,
+        new_token: &str,
+    ) -> Result<(), Error> {
+        let mut attributes = HashMap::new();
+        attributes.insert("Token".to_string(), new_token.to_string());
+
+        self.client
+            .set_endpoint_attributes(SetEndpointAttributesInput {
+                endpoint_arn: endpoint_arn.clone(),
+                attributes,
+            })
+            .sync()?;
+
+        Ok(())
+    }
+
+    /// Confirm a pending subscription.
+    ///
+    /// Call this with the [`SnsMessage`] SNS POSTs to a new HTTPS subscriber when its
+    /// `message_type` is [`MessageType::SubscriptionConfirmation`]. Until confirmed, SNS won't
+    /// deliver further notifications to it.
+    ///
+    /// Returns [`Error::NotASubscriptionConfirmation`] if `message` isn't a
+    /// [`MessageType::SubscriptionConfirmation`] or has no `token`.
+    pub fn confirm_subscription(&self, message: &SnsMessage) -> Result<SubscriptionArn, Error> {
+        if message.message_type != MessageType::SubscriptionConfirmation {
+            return Err(Error::NotASubscriptionConfirmation);
+        }
+
+        let token = message
+            .token
+            .clone()
+            .ok_or(Error::NotASubscriptionConfirmation)?;
+
+        let res = self
+            .client
+            .confirm_subscription(ConfirmSubscriptionInput {
+                topic_arn: message.topic_arn.clone(),
+                token,
+                ..Default::default()
+            })
+            .sync()?;
+
+        Ok(res.subscription_arn.unwrap())
+    }
 }
 
 /// An ID that identifies a single device.
 pub type EndpointArn = String;
 
+/// An ID that identifies a topic.
+pub type TopicArn = String;
+
+/// An ID that identifies a subscription to a topic.
+pub type SubscriptionArn = String;
+
+/// An ID that identifies a platform application.
+pub type PlatformApplicationArn = String;
+
+/// A push notification platform that SNS can deliver through.
+#[derive(Debug)]
+pub enum Platform {
+    /// Apple Push Notification Service, for production apps.
+    Apns,
+
+    /// Apple Push Notification Service, for development/sandbox builds.
+    ApnsSandbox,
+
+    /// Firebase/Google Cloud Messaging, for Android apps.
+    Gcm,
+}
+
+impl Platform {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Platform::Apns => "APNS",
+            Platform::ApnsSandbox => "APNS_SANDBOX",
+            Platform::Gcm => "GCM",
+        }
+    }
+}
+
+/// The credentials used to authenticate a platform application with APNs or FCM.
+#[derive(Debug)]
+pub struct PlatformCredentials {
+    /// For APNs, the PEM-formatted private key. For GCM, the FCM API key. This is the
+    /// `PlatformCredential` attribute.
+    pub credential: String,
+
+    /// For APNs, the PEM-formatted certificate. Not used for GCM. This is the
+    /// `PlatformPrincipal` attribute.
+    pub principal: Option<String>,
+}
+
+/// The JSON envelope SNS POSTs to HTTP(S) subscribers.
+///
+/// Deserialize this from the raw request body to handle inbound topic notifications and
+/// subscription/unsubscribe confirmations.
+#[derive(Debug, Deserialize)]
+pub struct SnsMessage {
+    /// What kind of message this is.
+    #[serde(rename = "Type")]
+    pub message_type: MessageType,
+
+    /// A unique ID assigned by SNS to this message.
+    #[serde(rename = "MessageId")]
+    pub message_id: String,
+
+    /// The topic this message was sent through.
+    #[serde(rename = "TopicArn")]
+    pub topic_arn: TopicArn,
+
+    /// The message body. For a [`MessageType::Notification`] this is the body of the message
+    /// published to the topic; for a confirmation it's a human-readable description from SNS.
+    #[serde(rename = "Message")]
+    pub message: String,
+
+    /// When SNS sent this message.
+    #[serde(rename = "Timestamp")]
+    pub timestamp: String,
+
+    /// For a [`MessageType::SubscriptionConfirmation`] or
+    /// [`MessageType::UnsubscribeConfirmation`], the URL that would confirm it if visited. Not
+    /// present for a [`MessageType::Notification`].
+    #[serde(rename = "SubscribeURL")]
+    pub subscribe_url: Option<String>,
+
+    /// Present for a [`MessageType::SubscriptionConfirmation`] or
+    /// [`MessageType::UnsubscribeConfirmation`]. For a subscription confirmation, this is the
+    /// token [`confirm_subscription`](SnsClient::confirm_subscription) confirms it with. Not
+    /// present for a [`MessageType::Notification`].
+    #[serde(rename = "Token")]
+    pub token: Option<String>,
+}
+
+/// The kind of message an [`SnsMessage`] carries.
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub enum MessageType {
+    /// A message published to a topic.
+    Notification,
+
+    /// Sent when an endpoint first subscribes to a topic. Must be confirmed with
+    /// [`confirm_subscription`](SnsClient::confirm_subscription) before SNS will deliver
+    /// further messages to it.
+    SubscriptionConfirmation,
+
+    /// Sent when a subscription is cancelled.
+    UnsubscribeConfirmation,
+}
+
 /// A push notification to be sent.
 #[derive(Debug)]
 pub enum Push {
     /// A normal alert style push.
     Alert {
-        /// The text that'll be shown on screen.
-        text: String,
+        /// The alert to show on screen.
+        alert: Alert,
 
         /// The badge count to set. Requires platform support.
         badge: Option<i32>,
+
+        /// Additional delivery options.
+        options: PushOptions,
     },
 
     /// A silent push.
@@ -129,36 +470,49 @@ pub enum Push {
     Silent {
         /// The badge count to set. Requires platform support.
         badge: Option<i32>,
+
+        /// Additional delivery options.
+        options: PushOptions,
     },
 }
 
 impl Push {
     fn to_sns_payload(&self) -> String {
         let (ios, android) = match self {
-            Push::Alert { text, badge } => {
-                let ios = json!({
-                    "aps": {
-                      "alert": text,
-                      "badge": badge,
-                    }
+            Push::Alert {
+                alert,
+                badge,
+                options,
+            } => {
+                let mut aps = json!({
+                    "alert": alert.to_json(),
+                    "badge": badge,
                 });
+                options.apply_to_aps(&mut aps);
 
-                let android = json!({
+                let ios = json!({ "aps": aps });
+
+                let mut android = json!({
                   "data": {
-                    "message": text,
+                    "message": alert.body,
                     "badge": badge,
                   }
                 });
 
+                if alert.is_localized() {
+                    android["notification"] = alert.to_fcm_notification();
+                }
+
                 (ios, android)
             }
-            Push::Silent { badge } => {
-                let ios = json!({
-                    "aps": {
-                        "content-available": 1,
-                        "badge": badge,
-                    }
+            Push::Silent { badge, options } => {
+                let mut aps = json!({
+                    "content-available": 1,
+                    "badge": badge,
                 });
+                options.apply_to_aps(&mut aps);
+
+                let ios = json!({ "aps": aps });
 
                 let android = json!({
                   "data": {}
@@ -177,12 +531,307 @@ impl Push {
 
         json_to_string(&payload)
     }
+
+    fn options(&self) -> &PushOptions {
+        match self {
+            Push::Alert { options, .. } => options,
+            Push::Silent { options, .. } => options,
+        }
+    }
+
+    fn to_message_attributes(&self) -> Option<HashMap<String, MessageAttributeValue>> {
+        let options = self.options();
+        let mut attributes = HashMap::new();
+
+        if let Some(priority) = options.priority {
+            attributes.insert(
+                "AWS.SNS.MOBILE.APNS.PRIORITY".to_string(),
+                string_attribute(priority.to_string()),
+            );
+        }
+
+        if let Some(expiration) = options.expiration {
+            attributes.insert(
+                "AWS.SNS.MOBILE.APNS.TTL".to_string(),
+                string_attribute(expiration.to_string()),
+            );
+        }
+
+        if let Some(collapse_id) = &options.collapse_id {
+            attributes.insert(
+                "AWS.SNS.MOBILE.APNS.COLLAPSE_ID".to_string(),
+                string_attribute(collapse_id.clone()),
+            );
+        }
+
+        if attributes.is_empty() {
+            None
+        } else {
+            Some(attributes)
+        }
+    }
+}
+
+/// The alert content of a [`Push::Alert`].
+///
+/// A plain [`Alert::new`] is shown as-is. Setting any of the localization fields switches to
+/// the APNs/FCM "localized alert" form, letting the receiving device substitute in strings from
+/// its own `Localizable.strings`/Android string resources instead of `body`.
+#[derive(Debug)]
+pub struct Alert {
+    /// The notification's title. Supported on both APNs and FCM.
+    pub title: Option<String>,
+
+    /// The notification's subtitle. APNs only.
+    pub subtitle: Option<String>,
+
+    /// The body text of the notification.
+    pub body: String,
+
+    /// A key identifying a localized string to substitute for `body` on the device.
+    pub loc_key: Option<String>,
+
+    /// Arguments to substitute into the localized string identified by `loc_key`.
+    pub loc_args: Vec<String>,
+
+    /// A key identifying a localized string to substitute for `title` on the device.
+    pub title_loc_key: Option<String>,
+
+    /// Arguments to substitute into the localized string identified by `title_loc_key`.
+    pub title_loc_args: Vec<String>,
+
+    /// A key identifying the localized title of the action button. APNs only.
+    pub action_loc_key: Option<String>,
+
+    /// The name of an image file to show while the notification is being loaded. APNs only.
+    pub launch_image: Option<String>,
+}
+
+impl Alert {
+    /// Create a plain alert with just a body, shown as-is.
+    pub fn new(body: impl Into<String>) -> Self {
+        Alert {
+            title: None,
+            subtitle: None,
+            body: body.into(),
+            loc_key: None,
+            loc_args: Vec::new(),
+            title_loc_key: None,
+            title_loc_args: Vec::new(),
+            action_loc_key: None,
+            launch_image: None,
+        }
+    }
+
+    fn is_localized(&self) -> bool {
+        self.title.is_some()
+            || self.subtitle.is_some()
+            || self.loc_key.is_some()
+            || !self.loc_args.is_empty()
+            || self.title_loc_key.is_some()
+            || !self.title_loc_args.is_empty()
+            || self.action_loc_key.is_some()
+            || self.launch_image.is_some()
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        if !self.is_localized() {
+            return json!(self.body);
+        }
+
+        let mut alert = serde_json::Map::new();
+        alert.insert("body".to_string(), json!(self.body));
+
+        if let Some(title) = &self.title {
+            alert.insert("title".to_string(), json!(title));
+        }
+        if let Some(subtitle) = &self.subtitle {
+            alert.insert("subtitle".to_string(), json!(subtitle));
+        }
+        if let Some(loc_key) = &self.loc_key {
+            alert.insert("loc-key".to_string(), json!(loc_key));
+        }
+        if !self.loc_args.is_empty() {
+            alert.insert("loc-args".to_string(), json!(self.loc_args));
+        }
+        if let Some(title_loc_key) = &self.title_loc_key {
+            alert.insert("title-loc-key".to_string(), json!(title_loc_key));
+        }
+        if !self.title_loc_args.is_empty() {
+            alert.insert("title-loc-args".to_string(), json!(self.title_loc_args));
+        }
+        if let Some(action_loc_key) = &self.action_loc_key {
+            alert.insert("action-loc-key".to_string(), json!(action_loc_key));
+        }
+        if let Some(launch_image) = &self.launch_image {
+            alert.insert("launch-image".to_string(), json!(launch_image));
+        }
+
+        serde_json::Value::Object(alert)
+    }
+
+    fn to_fcm_notification(&self) -> serde_json::Value {
+        let mut notification = serde_json::Map::new();
+        notification.insert("body".to_string(), json!(self.body));
+
+        if let Some(title) = &self.title {
+            notification.insert("title".to_string(), json!(title));
+        }
+        if let Some(loc_key) = &self.loc_key {
+            notification.insert("body_loc_key".to_string(), json!(loc_key));
+        }
+        if !self.loc_args.is_empty() {
+            notification.insert("body_loc_args".to_string(), json!(self.loc_args));
+        }
+        if let Some(title_loc_key) = &self.title_loc_key {
+            notification.insert("title_loc_key".to_string(), json!(title_loc_key));
+        }
+        if !self.title_loc_args.is_empty() {
+            notification.insert("title_loc_args".to_string(), json!(self.title_loc_args));
+        }
+
+        serde_json::Value::Object(notification)
+    }
+}
+
+/// Additional delivery options for a [`Push`].
+///
+/// These map to APNs payload keys and headers. Android delivery is unaffected, since GCM/FCM has
+/// no equivalent concept for most of these fields.
+#[derive(Debug, Default)]
+pub struct PushOptions {
+    /// The name of a sound file in the app bundle to play when the notification is delivered.
+    pub sound: Option<String>,
+
+    /// The notification's category, used to identify a set of actions to display.
+    pub category: Option<String>,
+
+    /// An identifier used to group related notifications together.
+    pub thread_id: Option<String>,
+
+    /// Whether the system should wake the app's notification service extension to modify the
+    /// notification's content before it's displayed.
+    pub mutable_content: bool,
+
+    /// Whether to wake the app in the background to let it process the notification, even
+    /// though it carries an alert. Requires platform support.
+    pub content_available: bool,
+
+    /// Delivery priority, sent to APNs as the `apns-priority` header. Use `10` for immediate
+    /// delivery, or `5` to conserve the device's power.
+    pub priority: Option<u8>,
+
+    /// Unix timestamp after which APNs will stop trying to deliver the notification, sent as
+    /// the `apns-expiration` header.
+    pub expiration: Option<i64>,
+
+    /// Identifier used by APNs to coalesce similar notifications, sent as the
+    /// `apns-collapse-id` header.
+    pub collapse_id: Option<String>,
+}
+
+impl PushOptions {
+    fn apply_to_aps(&self, aps: &mut serde_json::Value) {
+        let aps = aps.as_object_mut().unwrap();
+
+        if let Some(sound) = &self.sound {
+            aps.insert("sound".to_string(), json!(sound));
+        }
+
+        if let Some(category) = &self.category {
+            aps.insert("category".to_string(), json!(category));
+        }
+
+        if let Some(thread_id) = &self.thread_id {
+            aps.insert("thread-id".to_string(), json!(thread_id));
+        }
+
+        if self.mutable_content {
+            aps.insert("mutable-content".to_string(), json!(1));
+        }
+
+        if self.content_available {
+            aps.insert("content-available".to_string(), json!(1));
+        }
+    }
 }
 
 fn json_to_string<S: Serialize>(s: &S) -> String {
     serde_json::to_string(s).unwrap()
 }
 
+/// An SMS message to be sent directly to a phone number, rather than to a registered device.
+#[derive(Debug)]
+pub struct Sms {
+    /// The text of the message.
+    pub message: String,
+
+    /// A short identifier, displayed as the message sender on devices that support it.
+    pub sender_id: Option<String>,
+
+    /// The maximum price, in USD, that you're willing to pay to send this message.
+    pub max_price: Option<f64>,
+
+    /// Whether this is a transactional or promotional message.
+    pub sms_type: SmsType,
+}
+
+impl Sms {
+    fn to_message_attributes(&self) -> HashMap<String, MessageAttributeValue> {
+        let mut attributes = HashMap::new();
+
+        if let Some(sender_id) = &self.sender_id {
+            attributes.insert(
+                "AWS.SNS.SMS.SenderID".to_string(),
+                string_attribute(sender_id.clone()),
+            );
+        }
+
+        if let Some(max_price) = self.max_price {
+            attributes.insert(
+                "AWS.SNS.SMS.MaxPrice".to_string(),
+                string_attribute(max_price.to_string()),
+            );
+        }
+
+        attributes.insert(
+            "AWS.SNS.SMS.SMSType".to_string(),
+            string_attribute(self.sms_type.as_str().to_string()),
+        );
+
+        attributes
+    }
+}
+
+/// The type of an [`Sms`] message.
+///
+/// SNS uses this to pick the most cost effective or most reliable delivery route.
+#[derive(Debug)]
+pub enum SmsType {
+    /// A non-critical message, such as a marketing message.
+    Promotional,
+
+    /// A critical message that supports a customer transaction, such as a one-time passcode.
+    Transactional,
+}
+
+impl SmsType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SmsType::Promotional => "Promotional",
+            SmsType::Transactional => "Transactional",
+        }
+    }
+}
+
+fn string_attribute(value: String) -> MessageAttributeValue {
+    MessageAttributeValue {
+        data_type: "String".to_string(),
+        string_value: Some(value),
+        ..Default::default()
+    }
+}
+
 /// The errors this library might generate.
 #[derive(Debug)]
 pub enum Error {
@@ -192,6 +841,25 @@ pub enum Error {
     /// An error related to publishing a push.
     RegisterDeviceError(CreatePlatformEndpointError),
 
+    /// An error related to creating a topic.
+    CreateTopicError(CreateTopicError),
+
+    /// An error related to subscribing to a topic.
+    SubscribeError(SubscribeError),
+
+    /// An error related to creating a platform application.
+    CreatePlatformApplicationError(CreatePlatformApplicationError),
+
+    /// An error related to updating an endpoint's attributes.
+    SetEndpointAttributesError(SetEndpointAttributesError),
+
+    /// An error related to confirming a subscription.
+    ConfirmSubscriptionError(ConfirmSubscriptionError),
+
+    /// [`SnsClient::confirm_subscription`] was called with an [`SnsMessage`] that isn't a
+    /// [`MessageType::SubscriptionConfirmation`], or that has no `token`.
+    NotASubscriptionConfirmation,
+
     /// An error related to missing credential environment variables.
     MissingCredentials(MissingCredentials),
 }
@@ -219,6 +887,36 @@ impl From<PublishError> for Error {
     }
 }
 
+impl From<CreateTopicError> for Error {
+    fn from(inner: CreateTopicError) -> Self {
+        Error::CreateTopicError(inner)
+    }
+}
+
+impl From<SubscribeError> for Error {
+    fn from(inner: SubscribeError) -> Self {
+        Error::SubscribeError(inner)
+    }
+}
+
+impl From<CreatePlatformApplicationError> for Error {
+    fn from(inner: CreatePlatformApplicationError) -> Self {
+        Error::CreatePlatformApplicationError(inner)
+    }
+}
+
+impl From<SetEndpointAttributesError> for Error {
+    fn from(inner: SetEndpointAttributesError) -> Self {
+        Error::SetEndpointAttributesError(inner)
+    }
+}
+
+impl From<ConfirmSubscriptionError> for Error {
+    fn from(inner: ConfirmSubscriptionError) -> Self {
+        Error::ConfirmSubscriptionError(inner)
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl fmt::Display for Error {
@@ -226,6 +924,14 @@ impl fmt::Display for Error {
         match self {
             Error::PublishError(inner) => write!(f, "{}", inner),
             Error::RegisterDeviceError(inner) => write!(f, "{}", inner),
+            Error::CreateTopicError(inner) => write!(f, "{}", inner),
+            Error::SubscribeError(inner) => write!(f, "{}", inner),
+            Error::CreatePlatformApplicationError(inner) => write!(f, "{}", inner),
+            Error::SetEndpointAttributesError(inner) => write!(f, "{}", inner),
+            Error::ConfirmSubscriptionError(inner) => write!(f, "{}", inner),
+            Error::NotASubscriptionConfirmation => {
+                write!(f, "`SnsMessage` is not a subscription confirmation")
+            }
 
             Error::MissingCredentials(MissingCredentials::AccessKeyId) => {
                 write!(f, "`AWS_ACCESS_KEY_ID` env var is missing")